@@ -1,4 +1,4 @@
-//! Sign commit data.
+//! Sign and verify commit data.
 
 /// Error type for [`SignBuilder`], used to create [`Sign`]'s
 #[derive(thiserror::Error, Debug)]
@@ -15,6 +15,10 @@ pub enum SignBuilderError {
 	#[error("Failed to build signing signature: {0}")]
 	Signature(String),
 
+	/// `gitui.signing.keyFile` is required for the native `rust` signing method
+	#[error("Failed to retrieve 'gitui.signing.keyFile' from the git configuration: {0}")]
+	KeyFile(String),
+
 	/// Failure on unimplemented signing methods
 	/// to be removed once all methods have been implemented
 	#[error("Select signing method '{0}' has not been implemented")]
@@ -43,6 +47,40 @@ pub enum SignError {
 	/// Failure of the child process
 	#[error("Failed to execute signing process: {0}")]
 	Shellout(String),
+
+	/// No `user.signingKey` was configured and `ssh-agent` does not hold any identities
+	/// to fall back on
+	#[error("No SSH signing key configured and no identities found in ssh-agent: {0}")]
+	SSHAgentEmpty(String),
+
+	/// No usable signing key could be determined from the configuration or environment
+	#[error("No SSH signing key available: {0}")]
+	SSHNoKeyAvailable(String),
+
+	/// The configured key file could not be read or parsed as a valid key
+	#[error("Failed to read signing key file: {0}")]
+	KeyFile(String),
+
+	/// The SSH private key file could not be parsed
+	#[error("Failed to parse SSH signing key: {0}")]
+	SSHKeyParse(String),
+
+	/// The SSH private key could not be decrypted, e.g. a wrong passphrase
+	#[error("Failed to decrypt SSH signing key: {0}")]
+	SSHKeyDecrypt(String),
+
+	/// Failed to retrieve a cached passphrase from the platform secret store
+	#[error("Failed to retrieve passphrase from OS keyring: {0}")]
+	KeyringGet(String),
+
+	/// Failed to cache a passphrase in the platform secret store
+	#[error("Failed to store passphrase in OS keyring: {0}")]
+	KeyringSet(String),
+
+	/// No usable passphrase is cached for a passphrase-protected SSH signing key;
+	/// the caller must collect one (see [`SSHSign::cache_passphrase`]) and retry
+	#[error("SSH signing key passphrase required: {0}")]
+	SSHPassphraseRequired(String),
 }
 
 /// Sign commit data using various methods
@@ -94,7 +132,7 @@ impl SignBuilder {
 	pub fn from_gitconfig(
 		repo: &git2::Repository,
 		config: &git2::Config,
-	) -> Result<impl Sign, SignBuilderError> {
+	) -> Result<Box<dyn Sign>, SignBuilderError> {
 		let signing_methods = config
 			.get_string("gitui.signing_methods")
 			.unwrap_or_else(|_| "shellouts".to_string());
@@ -147,27 +185,75 @@ impl SignBuilder {
 								)
 							})?;
 
-						Ok(GPGSign {
+						Ok(Box::new(GPGSign {
 							program,
 							signing_key,
-						})
+						}))
 					}
 					"x509" => {
-						Err(SignBuilderError::MethodNotImplemented(
-							String::from("x509"),
-						))
+						// Unlike the openpgp format, `gpg.program` is not a fallback here:
+						// x509/CMS signatures are produced by a dedicated binary.
+						// https://git-scm.com/docs/git-config#Documentation/git-config.txt-gpgx509program
+						let program = config
+							.get_string("gpg.x509.program")
+							.unwrap_or_else(|_| "gpgsm".to_string());
+
+						let signing_key = config
+							.get_string("user.signingKey")
+							.map_err(|err| {
+								SignBuilderError::GPGSigningKey(
+									err.to_string(),
+								)
+							})?;
+
+						Ok(Box::new(X509Sign {
+							program,
+							signing_key,
+						}))
 					}
 					"ssh" => {
-						Err(SignBuilderError::MethodNotImplemented(
-							String::from("ssh"),
-						))
+						// https://git-scm.com/docs/git-config#Documentation/git-config.txt-gpgsshprogram
+						let program = config
+							.get_string("gpg.ssh.program")
+							.unwrap_or_else(|_| {
+								"ssh-keygen".to_string()
+							});
+
+						// `user.signingKey` may be a literal public key (e.g. `ssh-ed25519 AAAA...`),
+						// a path to a key file, or unset entirely, in which case the first identity
+						// offered by `ssh-agent` is used.
+						// https://git-scm.com/docs/git-config#Documentation/git-config.txt-usersigningKey
+						let signing_key = config
+							.get_string("user.signingKey")
+							.unwrap_or_default();
+
+						// Decrypt passphrase-protected key files in-process rather than
+						// leaving `ssh-keygen` to prompt, using a passphrase cached in the OS keyring.
+						let cache_passphrase = config
+							.get_bool(
+								"gitui.signing.cachePassphrase",
+							)
+							.unwrap_or(false);
+
+						Ok(Box::new(SSHSign {
+							program,
+							signing_key,
+							cache_passphrase,
+						}))
 					}
 					_ => Err(SignBuilderError::InvalidFormat(format)),
 				}
 			}
-			"rust" => Err(SignBuilderError::MethodNotImplemented(
-				String::from("<rust native>"),
-			)),
+			"rust" => {
+				// Path to an exported secret key, used instead of shelling out to `gpg`.
+				let key_file = config
+					.get_string("gitui.signing.keyFile")
+					.map_err(|err| {
+						SignBuilderError::KeyFile(err.to_string())
+					})?;
+
+				Ok(Box::new(RustSign { key_file }))
+			}
 			_ => {
 				Err(SignBuilderError::InvalidFormat(signing_methods))
 			}
@@ -175,6 +261,101 @@ impl SignBuilder {
 	}
 }
 
+/// The result of running a detached-signature program to completion via [`run_detached_sign`].
+struct DetachedSignOutput {
+	status: std::process::ExitStatus,
+	stdout: Vec<u8>,
+	stderr: Vec<u8>,
+	/// `Err` if writing the commit buffer to stdin failed for a reason other than the
+	/// child hanging up early (a broken pipe there isn't our error to report).
+	write_result: Result<(), String>,
+}
+
+/// Run `program` with `args`, feeding it `commit` on stdin and collecting its stdout/stderr,
+/// shared by the `gpg` and `gpgsm` shellout backends.
+///
+/// Stdin is written on its own thread and stderr is drained on its own thread while
+/// stdout is read on the calling thread: if the program exits early (bad key, cancelled pin-entry) it stops reading
+/// stdin, and writing the full commit buffer on the calling thread could otherwise either
+/// fail with a confusing broken-pipe error or deadlock once a pipe buffer fills up while
+/// we're blocked reading a different one.
+fn run_detached_sign(
+	program: &str,
+	args: &[&str],
+	commit: &str,
+) -> Result<DetachedSignOutput, SignError> {
+	use std::io::{Read, Write};
+	use std::process::{Command, Stdio};
+
+	let mut cmd = Command::new(program);
+	cmd.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.args(args);
+
+	log::trace!("signing command: {cmd:?}");
+
+	let mut child =
+		cmd.spawn().map_err(|e| SignError::Spawn(e.to_string()))?;
+
+	let mut stdin = child.stdin.take().ok_or(SignError::Stdin)?;
+	let mut stdout = child.stdout.take().ok_or_else(|| {
+		SignError::Output(String::from("failed to acquire stdout"))
+	})?;
+	let mut stderr = child.stderr.take().ok_or_else(|| {
+		SignError::Output(String::from("failed to acquire stderr"))
+	})?;
+
+	let commit = commit.to_string();
+	let writer = std::thread::spawn(move || {
+		match stdin.write_all(commit.as_bytes()) {
+			Ok(()) => Ok(()),
+			Err(err)
+				if err.kind() == std::io::ErrorKind::BrokenPipe =>
+			{
+				Ok(())
+			}
+			Err(err) => Err(err.to_string()),
+		}
+	});
+
+	let stderr_reader = std::thread::spawn(move || {
+		let mut buf = Vec::new();
+		stderr.read_to_end(&mut buf).map(|_| buf)
+	});
+
+	let mut stdout_buf = Vec::new();
+	stdout
+		.read_to_end(&mut stdout_buf)
+		.map_err(|e| SignError::Output(e.to_string()))?;
+
+	let status = child
+		.wait()
+		.map_err(|e| SignError::Output(e.to_string()))?;
+
+	let write_result = writer.join().map_err(|_| {
+		SignError::WriteBuffer(String::from(
+			"stdin writer thread panicked",
+		))
+	})?;
+
+	let stderr_buf = stderr_reader
+		.join()
+		.map_err(|_| {
+			SignError::Output(String::from(
+				"stderr reader thread panicked",
+			))
+		})?
+		.map_err(|e| SignError::Output(e.to_string()))?;
+
+	Ok(DetachedSignOutput {
+		status,
+		stdout: stdout_buf,
+		stderr: stderr_buf,
+		write_result,
+	})
+}
+
 /// Sign commit data using `OpenPGP`
 pub struct GPGSign {
 	program: String,
@@ -193,45 +374,95 @@ impl GPGSign {
 
 impl Sign for GPGSign {
 	fn sign(&self, commit: &str) -> Result<String, SignError> {
-		use std::io::Write;
-		use std::process::{Command, Stdio};
+		let output = run_detached_sign(
+			&self.program,
+			&["--status-fd=2", "-bsau", &self.signing_key],
+			commit,
+		)?;
 
-		let mut cmd = Command::new(&self.program);
-		cmd.stdin(Stdio::piped())
-			.stdout(Stdio::piped())
-			.stderr(Stdio::piped())
-			.arg("--status-fd=2")
-			.arg("-bsau")
-			.arg(&self.signing_key);
+		let stderr = std::str::from_utf8(&output.stderr)
+			.map_err(|e| SignError::Shellout(e.to_string()))?;
 
-		log::trace!("signing command: {cmd:?}");
+		if !output.status.success() {
+			return Err(SignError::Shellout(format!(
+				"failed to sign data, program '{}' exited non-zero: {}",
+				&self.program, stderr
+			)));
+		}
 
-		let mut child = cmd
-			.spawn()
-			.map_err(|e| SignError::Spawn(e.to_string()))?;
+		if let Err(err) = output.write_result {
+			return Err(SignError::WriteBuffer(err));
+		}
 
-		let mut stdin = child.stdin.take().ok_or(SignError::Stdin)?;
+		if !stderr.contains("\n[GNUPG:] SIG_CREATED ") {
+			return Err(SignError::Shellout(
+				format!("failed to sign data, program '{}' failed, SIG_CREATED not seen in stderr", &self.program),
+			));
+		}
 
-		write!(stdin, "{commit}")
-			.map_err(|e| SignError::WriteBuffer(e.to_string()))?;
-		drop(stdin); // close stdin to not block indefinitely
+		let signed_commit = std::str::from_utf8(&output.stdout)
+			.map_err(|e| SignError::Shellout(e.to_string()))?;
 
-		let output = child
-			.wait_with_output()
-			.map_err(|e| SignError::Output(e.to_string()))?;
+		Ok(signed_commit.to_string())
+	}
+
+	#[cfg(test)]
+	fn program(&self) -> &String {
+		&self.program
+	}
+
+	#[cfg(test)]
+	fn signing_key(&self) -> &String {
+		&self.signing_key
+	}
+}
+
+/// Sign commit data using X.509/CMS certificates, mirroring git's `gpg.format = x509` support.
+pub struct X509Sign {
+	program: String,
+	signing_key: String,
+}
+
+impl X509Sign {
+	/// Create new [`X509Sign`] using given program and signing key.
+	pub fn new(program: &str, signing_key: &str) -> Self {
+		Self {
+			program: program.to_string(),
+			signing_key: signing_key.to_string(),
+		}
+	}
+}
+
+impl Sign for X509Sign {
+	fn sign(&self, commit: &str) -> Result<String, SignError> {
+		let output = run_detached_sign(
+			&self.program,
+			&[
+				"--status-fd=2",
+				"--detach-sign",
+				"--armor",
+				"--local-user",
+				&self.signing_key,
+			],
+			commit,
+		)?;
+
+		let stderr = std::str::from_utf8(&output.stderr)
+			.map_err(|e| SignError::Shellout(e.to_string()))?;
 
 		if !output.status.success() {
 			return Err(SignError::Shellout(format!(
 				"failed to sign data, program '{}' exited non-zero: {}",
-				&self.program,
-				std::str::from_utf8(&output.stderr)
-					.unwrap_or("[error could not be read from stderr]")
+				&self.program, stderr
 			)));
 		}
 
-		let stderr = std::str::from_utf8(&output.stderr)
-			.map_err(|e| SignError::Shellout(e.to_string()))?;
+		if let Err(err) = output.write_result {
+			return Err(SignError::WriteBuffer(err));
+		}
 
+		// gpgsm shares GnuPG's common status protocol, so a successful
+		// detached signature is still reported as a SIG_CREATED status line.
 		if !stderr.contains("\n[GNUPG:] SIG_CREATED ") {
 			return Err(SignError::Shellout(
 				format!("failed to sign data, program '{}' failed, SIG_CREATED not seen in stderr", &self.program),
@@ -255,88 +486,1170 @@ impl Sign for GPGSign {
 	}
 }
 
-#[cfg(test)]
-mod tests {
-	use super::*;
-	use crate::error::Result;
-	use crate::sync::tests::repo_init_empty;
-
-	#[test]
-	fn test_invalid_signing_format() -> Result<()> {
-		let (_temp_dir, repo) = repo_init_empty()?;
+/// Sign commit data using SSH keys via `ssh-keygen -Y sign`, mirroring git's `gpg.format = ssh` support.
+pub struct SSHSign {
+	program: String,
+	signing_key: String,
+	/// When set (`gitui.signing.cachePassphrase`), decrypt passphrase-protected key files
+	/// in-process instead of passing them to `ssh-keygen`, using a passphrase cached in
+	/// the platform secret store (see [`SSHSign::cache_passphrase`]).
+	cache_passphrase: bool,
+}
 
-		{
-			let mut config = repo.config()?;
-			config.set_str("gpg.format", "INVALID_SIGNING_FORMAT")?;
+impl SSHSign {
+	/// Create new [`SSHSign`] using given program and signing key.
+	pub fn new(
+		program: &str,
+		signing_key: &str,
+		cache_passphrase: bool,
+	) -> Self {
+		Self {
+			program: program.to_string(),
+			signing_key: signing_key.to_string(),
+			cache_passphrase,
 		}
+	}
 
-		let sign =
-			SignBuilder::from_gitconfig(&repo, &repo.config()?);
+	/// A public key starts with its algorithm name (`ssh-ed25519`, `ssh-rsa`, `ecdsa-sha2-...`),
+	/// as opposed to a filesystem path to a key file.
+	fn is_literal_public_key(value: &str) -> bool {
+		value.starts_with("ssh-")
+			|| value.starts_with("ecdsa-sha2-")
+			|| value.starts_with("sk-")
+	}
 
-		assert!(sign.is_err());
+	/// Fall back to the first identity offered by `ssh-agent` when no signing key is configured.
+	fn first_agent_identity() -> Result<String, SignError> {
+		use std::process::Command;
 
-		Ok(())
+		let output = Command::new("ssh-add")
+			.arg("-L")
+			.output()
+			.map_err(|e| SignError::Spawn(e.to_string()))?;
+
+		if !output.status.success() {
+			// `ssh-add -L` exits 1 and reports on stdout when the agent holds no identities
+			if output.status.code() == Some(1)
+				&& output.stderr.is_empty()
+			{
+				return Err(SignError::SSHAgentEmpty(
+					String::from_utf8_lossy(&output.stdout)
+						.trim()
+						.to_string(),
+				));
+			}
+			return Err(SignError::SSHNoKeyAvailable(
+				std::str::from_utf8(&output.stderr)
+					.unwrap_or("[error could not be read from stderr]")
+					.to_string(),
+			));
+		}
+
+		let stdout = std::str::from_utf8(&output.stdout)
+			.map_err(|e| SignError::Output(e.to_string()))?;
+
+		stdout
+			.lines()
+			.find(|line| !line.trim().is_empty())
+			.map(str::to_string)
+			.ok_or_else(|| {
+				SignError::SSHAgentEmpty(String::from(
+					"no identities found, see `ssh-add -L`",
+				))
+			})
 	}
 
-	#[test]
-	fn test_program_and_signing_key_defaults() -> Result<()> {
-		let (_tmp_dir, repo) = repo_init_empty()?;
-		let sign =
-			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+	/// Resolve the key file passed to `ssh-keygen -f`, materializing a temporary
+	/// public key file when `user.signingKey` (or `ssh-agent`) only gave us the key contents.
+	fn resolve_keyfile(
+		&self,
+		workdir: &std::path::Path,
+	) -> Result<std::path::PathBuf, SignError> {
+		let key = if self.signing_key.trim().is_empty() {
+			Self::first_agent_identity()?
+		} else if Self::is_literal_public_key(&self.signing_key) {
+			self.signing_key.clone()
+		} else {
+			let path = std::path::PathBuf::from(&self.signing_key);
+			return if self.cache_passphrase {
+				self.decrypt_keyfile(&path, workdir)
+			} else {
+				Ok(path)
+			};
+		};
 
-		assert_eq!("gpg", sign.program());
-		assert_eq!("name <email>", sign.signing_key());
+		let keyfile = workdir.join("signing_key.pub");
+		std::fs::write(&keyfile, format!("{key}\n"))
+			.map_err(|e| SignError::Output(e.to_string()))?;
 
-		Ok(())
+		Ok(keyfile)
 	}
 
-	#[test]
-	fn test_gpg_program_configs() -> Result<()> {
-		let (_tmp_dir, repo) = repo_init_empty()?;
+	/// Decrypt a passphrase-protected private key file in-process, rather than leaving
+	/// `ssh-keygen` to prompt for it on a terminal we don't control.
+	///
+	/// This never prompts: gitui owns the terminal (raw mode / alternate screen) and this
+	/// runs in asyncgit's sync layer, so collecting input here would corrupt the TUI or
+	/// hang it. The passphrase must already be cached in the platform secret store (see
+	/// [`SSHSign::cache_passphrase`]); if it isn't, or no longer unlocks the key, this
+	/// returns [`SignError::SSHPassphraseRequired`] for the UI to act on.
+	fn decrypt_keyfile(
+		&self,
+		path: &std::path::Path,
+		workdir: &std::path::Path,
+	) -> Result<std::path::PathBuf, SignError> {
+		use ssh_key::PrivateKey;
 
-		{
-			let mut config = repo.config()?;
-			config.set_str("gpg.program", "GPG_PROGRAM_TEST")?;
+		let raw = std::fs::read_to_string(path)
+			.map_err(|e| SignError::SSHKeyParse(e.to_string()))?;
+
+		let private_key = PrivateKey::from_openssh(&raw)
+			.map_err(|e| SignError::SSHKeyParse(e.to_string()))?;
+
+		if !private_key.is_encrypted() {
+			return Ok(path.to_path_buf());
 		}
 
-		let sign =
-			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+		let entry = keyring::Entry::new(
+			"gitui-ssh-signing",
+			&path.to_string_lossy(),
+		)
+		.map_err(|e| SignError::KeyringGet(e.to_string()))?;
 
-		// we get gpg.program, because gpg.openpgp.program is not set
-		assert_eq!("GPG_PROGRAM_TEST", sign.program());
+		let passphrase = entry.get_password().map_err(|err| {
+			match err {
+				keyring::Error::NoEntry => {
+					SignError::SSHPassphraseRequired(format!(
+						"no cached passphrase for SSH signing key '{}'",
+						path.display()
+					))
+				}
+				err => SignError::KeyringGet(err.to_string()),
+			}
+		})?;
+
+		let decrypted = private_key
+			.decrypt(passphrase.as_bytes())
+			.map_err(|err| {
+				// A cached passphrase that no longer unlocks the key (rotated, or a
+				// stale/foreign keyring entry) must not fail every sign forever: drop
+				// it so the next attempt asks the UI to collect a fresh one instead of
+				// silently reusing it.
+				let _ = entry.delete_password();
+				SignError::SSHPassphraseRequired(format!(
+					"cached passphrase for SSH signing key '{}' no longer works ({err}), cleared it",
+					path.display()
+				))
+			})?;
 
+		let openssh = decrypted
+			.to_openssh(ssh_key::LineEnding::LF)
+			.map_err(|e| SignError::SSHKeyParse(e.to_string()))?;
+
+		let decrypted_path = workdir.join("signing_key");
+		std::fs::write(&decrypted_path, openssh.as_bytes())
+			.map_err(|e| SignError::Output(e.to_string()))?;
+
+		#[cfg(unix)]
 		{
-			let mut config = repo.config()?;
-			config.set_str(
-				"gpg.openpgp.program",
-				"GPG_OPENPGP_PROGRAM_TEST",
-			)?;
+			use std::os::unix::fs::PermissionsExt;
+			std::fs::set_permissions(
+				&decrypted_path,
+				std::fs::Permissions::from_mode(0o600),
+			)
+			.map_err(|e| SignError::Output(e.to_string()))?;
 		}
 
-		let sign =
-			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+		Ok(decrypted_path)
+	}
 
-		// since gpg.openpgp.program is now set as well, it is more specific than
-		// gpg.program and therefore takes precedence
-		assert_eq!("GPG_OPENPGP_PROGRAM_TEST", sign.program());
+	/// Cache a passphrase for a passphrase-protected SSH signing key, keyed by the key's
+	/// path, in the platform secret store. Call this from the UI once it has collected the
+	/// passphrase from the user (e.g. in response to a [`SignError::SSHPassphraseRequired`]
+	/// error) — [`Sign::sign`] itself never prompts.
+	pub fn cache_passphrase(
+		key_path: &str,
+		passphrase: &str,
+	) -> Result<(), SignError> {
+		let entry = keyring::Entry::new("gitui-ssh-signing", key_path)
+			.map_err(|e| SignError::KeyringGet(e.to_string()))?;
 
-		Ok(())
+		entry
+			.set_password(passphrase)
+			.map_err(|e| SignError::KeyringSet(e.to_string()))
 	}
+}
 
-	#[test]
-	fn test_user_signingkey() -> Result<()> {
-		let (_tmp_dir, repo) = repo_init_empty()?;
+impl Sign for SSHSign {
+	fn sign(&self, commit: &str) -> Result<String, SignError> {
+		use std::process::{Command, Stdio};
 
-		{
-			let mut config = repo.config()?;
-			config.set_str("user.signingKey", "FFAA")?;
+		let workdir = sign_tempdir("sign");
+		std::fs::create_dir_all(&workdir)
+			.map_err(|e| SignError::Output(e.to_string()))?;
+
+		let keyfile = match self.resolve_keyfile(&workdir) {
+			Ok(keyfile) => keyfile,
+			Err(e) => {
+				let _ = std::fs::remove_dir_all(&workdir);
+				return Err(e);
+			}
+		};
+
+		// `ssh-keygen -Y sign` takes the data to sign as a file argument, not on stdin,
+		// and writes the signature alongside it as `<file>.sig`.
+		let datafile = workdir.join("commit");
+		std::fs::write(&datafile, commit)
+			.map_err(|e| SignError::WriteBuffer(e.to_string()))?;
+
+		let mut cmd = Command::new(&self.program);
+		cmd.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.arg("-Y")
+			.arg("sign")
+			.arg("-n")
+			.arg("git")
+			.arg("-f")
+			.arg(&keyfile)
+			.arg(&datafile);
+
+		log::trace!("signing command: {cmd:?}");
+
+		let output = cmd
+			.output()
+			.map_err(|e| SignError::Spawn(e.to_string()))?;
+
+		if !output.status.success() {
+			let _ = std::fs::remove_dir_all(&workdir);
+			return Err(SignError::Shellout(format!(
+				"failed to sign data, program '{}' exited non-zero: {}",
+				&self.program,
+				std::str::from_utf8(&output.stderr)
+					.unwrap_or("[error could not be read from stderr]")
+			)));
 		}
 
-		let sign =
-			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+		let signature =
+			std::fs::read_to_string(datafile.with_extension("sig"))
+				.map_err(|e| SignError::Output(e.to_string()));
 
-		assert_eq!("FFAA", sign.signing_key());
+		let _ = std::fs::remove_dir_all(&workdir);
 
-		Ok(())
+		signature
+	}
+
+	#[cfg(test)]
+	fn program(&self) -> &String {
+		&self.program
+	}
+
+	#[cfg(test)]
+	fn signing_key(&self) -> &String {
+		&self.signing_key
+	}
+}
+
+/// Sign commit data natively using `sequoia-openpgp`, without shelling out to a `gpg` binary.
+///
+/// Selected via `gitui.signing_methods = "rust"`. The secret key is loaded from the file
+/// named by `gitui.signing.keyFile`, which must contain an exported `OpenPGP` key.
+pub struct RustSign {
+	key_file: String,
+}
+
+impl RustSign {
+	/// Create new [`RustSign`] using the given exported key file.
+	pub fn new(key_file: &str) -> Self {
+		Self {
+			key_file: key_file.to_string(),
+		}
+	}
+}
+
+impl Sign for RustSign {
+	fn sign(&self, commit: &str) -> Result<String, SignError> {
+		use sequoia_openpgp::cert::Cert;
+		use sequoia_openpgp::parse::Parse;
+		use sequoia_openpgp::policy::StandardPolicy;
+		use sequoia_openpgp::serialize::stream::{
+			Armorer, Message, Signer,
+		};
+		use std::io::Write;
+
+		let policy = StandardPolicy::new();
+
+		let cert = Cert::from_file(&self.key_file)
+			.map_err(|e| SignError::KeyFile(e.to_string()))?;
+
+		let keypair = cert
+			.keys()
+			.with_policy(&policy, None)
+			.secret()
+			.for_signing()
+			.next()
+			.ok_or_else(|| {
+				SignError::KeyFile(String::from(
+					"key file does not contain a signing-capable subkey",
+				))
+			})?
+			.key()
+			.clone()
+			.into_keypair()
+			.map_err(|e| SignError::KeyFile(e.to_string()))?;
+
+		let mut armored = Vec::new();
+		{
+			let message = Message::new(&mut armored);
+			let message = Armorer::new(message)
+				// A detached commit signature is an armored `SIGNATURE` block, not
+				// the `MESSAGE` block `Armorer` produces by default.
+				.kind(sequoia_openpgp::armor::Kind::Signature)
+				.build()
+				.map_err(|e| SignError::Output(e.to_string()))?;
+			let mut signer = Signer::new(message, keypair)
+				.detached()
+				.build()
+				.map_err(|e| SignError::Output(e.to_string()))?;
+
+			signer
+				.write_all(commit.as_bytes())
+				.map_err(|e| SignError::WriteBuffer(e.to_string()))?;
+			signer
+				.finalize()
+				.map_err(|e| SignError::Output(e.to_string()))?;
+		}
+
+		String::from_utf8(armored)
+			.map_err(|e| SignError::Output(e.to_string()))
+	}
+
+	#[cfg(test)]
+	fn program(&self) -> &String {
+		// no external program is involved; expose the key file path instead
+		&self.key_file
+	}
+
+	#[cfg(test)]
+	fn signing_key(&self) -> &String {
+		&self.key_file
+	}
+}
+
+/// The result of checking a commit signature, analogous to git's `%G?` log format placeholder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigStatus {
+	/// The signature is valid and was made by a fully trusted key.
+	Good,
+	/// The signature does not verify.
+	Bad,
+	/// The signature could not be conclusively checked, e.g. the signing key is unknown
+	/// or its trust is undefined.
+	Unknown,
+}
+
+/// The structured outcome of a [`Verify::verify`] call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationResult {
+	/// Overall status of the signature.
+	pub status: SigStatus,
+	/// Identity of the signer, if one could be determined.
+	pub signer: Option<String>,
+	/// Fingerprint of the key used to create the signature, if one could be determined.
+	pub fingerprint: Option<String>,
+	/// Diagnostic text captured from the verification program, present whenever
+	/// `status` is not [`SigStatus::Good`].
+	pub error: Option<String>,
+}
+
+/// Error type for [`VerifyBuilder`], used to create [`Verify`]'s
+#[derive(thiserror::Error, Debug)]
+pub enum VerifyBuilderError {
+	/// The given format is invalid
+	#[error("Failed to derive a commit verification method from git configuration 'gpg.format': {0}")]
+	InvalidFormat(String),
+
+	/// `gpg.ssh.allowedSignersFile` is required to verify ssh signatures
+	#[error("Failed to retrieve 'gpg.ssh.allowedSignersFile' from the git configuration: {0}")]
+	AllowedSignersFile(String),
+}
+
+/// Error type for [`Verify`], used to verify signed data
+#[derive(thiserror::Error, Debug)]
+pub enum VerifyError {
+	/// Unable to spawn process
+	#[error("Failed to spawn verification process: {0}")]
+	Spawn(String),
+
+	/// Unable to acquire the child process' standard input to write the commit data for verification
+	#[error("Failed to acquire standard input handler")]
+	Stdin,
+
+	/// Unable to write commit data to verify to standard input of the child process
+	#[error("Failed to write buffer to standard input of verification process: {0}")]
+	WriteBuffer(String),
+
+	/// Unable to retrieve the output of the child process
+	#[error("Failed to get output of verification process call: {0}")]
+	Output(String),
+
+	/// Failure of the child process
+	#[error("Failed to execute verification process: {0}")]
+	Shellout(String),
+}
+
+/// Verify signed commit data using various methods
+pub trait Verify {
+	/// Verify `signature` over the given `commit` buffer with the respective implementation.
+	///
+	/// Retrieve an implementation using [`VerifyBuilder::from_gitconfig`].
+	///
+	/// `commit` and `signature` are built the same way as for [`Sign::sign`]: `commit` is the
+	/// buffer produced by [`git2::Repository::commit_create_buffer`], and `signature` is the
+	/// armored signature stored alongside it (see [`git2::Repository::extract_signature`]).
+	fn verify(
+		&self,
+		commit: &str,
+		signature: &str,
+	) -> Result<VerificationResult, VerifyError>;
+
+	#[cfg(test)]
+	fn program(&self) -> &String;
+}
+
+/// A builder to facilitate the creation of a verification method ([`Verify`]) by examining the git configuration.
+pub struct VerifyBuilder;
+
+impl VerifyBuilder {
+	/// Get a [`Verify`] from the given repository configuration to verify signed commit data
+	pub fn from_gitconfig(
+		_repo: &git2::Repository,
+		config: &git2::Config,
+	) -> Result<Box<dyn Verify>, VerifyBuilderError> {
+		let format = config
+			.get_string("gpg.format")
+			.unwrap_or_else(|_| "openpgp".to_string());
+
+		// Variants are described in the git config documentation
+		// https://git-scm.com/docs/git-config#Documentation/git-config.txt-gpgformat
+		match format.as_str() {
+			"openpgp" => {
+				let program = config
+					.get_string("gpg.openpgp.program")
+					.or_else(|_| config.get_string("gpg.program"))
+					.unwrap_or_else(|_| "gpg".to_string());
+
+				Ok(Box::new(GPGVerify { program }))
+			}
+			"x509" => {
+				let program = config
+					.get_string("gpg.x509.program")
+					.unwrap_or_else(|_| "gpgsm".to_string());
+
+				Ok(Box::new(X509Verify { program }))
+			}
+			"ssh" => {
+				let program = config
+					.get_string("gpg.ssh.program")
+					.unwrap_or_else(|_| "ssh-keygen".to_string());
+
+				let allowed_signers = config
+					.get_string("gpg.ssh.allowedSignersFile")
+					.map_err(|err| {
+						VerifyBuilderError::AllowedSignersFile(
+							err.to_string(),
+						)
+					})?;
+
+				Ok(Box::new(SSHVerify {
+					program,
+					allowed_signers,
+				}))
+			}
+			_ => Err(VerifyBuilderError::InvalidFormat(format)),
+		}
+	}
+}
+
+/// Parse the common GnuPG `--status-fd` protocol, shared by both `gpg` and `gpgsm`.
+///
+/// https://github.com/gpg/gnupg/blob/master/doc/DETAILS
+fn parse_gnupg_status(stderr: &str) -> VerificationResult {
+	let mut status = SigStatus::Unknown;
+	let mut signer = None;
+	let mut fingerprint = None;
+
+	for line in stderr.lines() {
+		let Some(rest) = line.trim().strip_prefix("[GNUPG:] ")
+		else {
+			continue;
+		};
+
+		if let Some(id) = rest.strip_prefix("GOODSIG ") {
+			status = SigStatus::Good;
+			signer = id.splitn(2, ' ').nth(1).map(str::to_string);
+		} else if rest.starts_with("BADSIG ") {
+			status = SigStatus::Bad;
+		} else if let Some(id) = rest.strip_prefix("VALIDSIG ") {
+			fingerprint = id.split(' ').next().map(str::to_string);
+		} else if status == SigStatus::Good
+			&& (rest.starts_with("TRUST_UNDEFINED")
+				|| rest.starts_with("TRUST_NEVER")
+				|| rest.starts_with("TRUST_MARGINAL"))
+		{
+			// A technically-good signature made by a key we don't fully trust is not
+			// conclusive proof of authorship; git's `%G?` reports this as `U`, not `G`.
+			status = SigStatus::Unknown;
+		}
+	}
+
+	VerificationResult {
+		error: (status != SigStatus::Good)
+			.then(|| stderr.to_string()),
+		status,
+		signer,
+		fingerprint,
+	}
+}
+
+/// Verify `OpenPGP` signatures by shelling out to `gpg`.
+pub struct GPGVerify {
+	program: String,
+}
+
+impl GPGVerify {
+	/// Create new [`GPGVerify`] using the given program.
+	pub fn new(program: &str) -> Self {
+		Self {
+			program: program.to_string(),
+		}
+	}
+}
+
+impl Verify for GPGVerify {
+	fn verify(
+		&self,
+		commit: &str,
+		signature: &str,
+	) -> Result<VerificationResult, VerifyError> {
+		verify_via_gnupg_status_protocol(
+			&self.program,
+			commit,
+			signature,
+		)
+	}
+
+	#[cfg(test)]
+	fn program(&self) -> &String {
+		&self.program
+	}
+}
+
+/// Verify X.509/CMS signatures by shelling out to `gpgsm`.
+pub struct X509Verify {
+	program: String,
+}
+
+impl X509Verify {
+	/// Create new [`X509Verify`] using the given program.
+	pub fn new(program: &str) -> Self {
+		Self {
+			program: program.to_string(),
+		}
+	}
+}
+
+impl Verify for X509Verify {
+	fn verify(
+		&self,
+		commit: &str,
+		signature: &str,
+	) -> Result<VerificationResult, VerifyError> {
+		verify_via_gnupg_status_protocol(
+			&self.program,
+			commit,
+			signature,
+		)
+	}
+
+	#[cfg(test)]
+	fn program(&self) -> &String {
+		&self.program
+	}
+}
+
+/// Shared `gpg`/`gpgsm` invocation: both accept `--status-fd=2 --verify <sigfile> <datafile>`
+/// and report the outcome via the same status-line protocol.
+fn verify_via_gnupg_status_protocol(
+	program: &str,
+	commit: &str,
+	signature: &str,
+) -> Result<VerificationResult, VerifyError> {
+	use std::process::{Command, Stdio};
+
+	let workdir = sign_tempdir("verify");
+	std::fs::create_dir_all(&workdir)
+		.map_err(|e| VerifyError::Output(e.to_string()))?;
+
+	let sigfile = workdir.join("commit.sig");
+	std::fs::write(&sigfile, signature)
+		.map_err(|e| VerifyError::WriteBuffer(e.to_string()))?;
+
+	let datafile = workdir.join("commit");
+	std::fs::write(&datafile, commit)
+		.map_err(|e| VerifyError::WriteBuffer(e.to_string()))?;
+
+	let mut cmd = Command::new(program);
+	cmd.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.arg("--status-fd=2")
+		.arg("--verify")
+		.arg(&sigfile)
+		.arg(&datafile);
+
+	log::trace!("verification command: {cmd:?}");
+
+	let output = cmd
+		.output()
+		.map_err(|e| VerifyError::Spawn(e.to_string()))?;
+
+	let _ = std::fs::remove_dir_all(&workdir);
+
+	let stderr = std::str::from_utf8(&output.stderr)
+		.map_err(|e| VerifyError::Shellout(e.to_string()))?;
+
+	Ok(parse_gnupg_status(stderr))
+}
+
+/// Verify SSH signatures by shelling out to `ssh-keygen -Y verify`.
+pub struct SSHVerify {
+	program: String,
+	allowed_signers: String,
+}
+
+impl SSHVerify {
+	/// Create new [`SSHVerify`] using the given program and allowed-signers file.
+	pub fn new(program: &str, allowed_signers: &str) -> Self {
+		Self {
+			program: program.to_string(),
+			allowed_signers: allowed_signers.to_string(),
+		}
+	}
+}
+
+/// `ssh-keygen -Y verify` conflates "signature didn't verify" with "signature verifies,
+/// but the signer's key isn't in the allowed-signers file" — both print `Could not verify
+/// signature.` and exit non-zero, so the verify output alone can't tell them apart. Ask
+/// `-Y find-principals` instead: it looks the signature's key up in the allowed-signers
+/// file directly and prints nothing (exit non-zero) if no principal maps to that key.
+fn classify_ssh_verify_failure(
+	program: &str,
+	allowed_signers: &str,
+	sigfile: &std::path::Path,
+) -> SigStatus {
+	use std::process::{Command, Stdio};
+
+	let mut cmd = Command::new(program);
+	cmd.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.arg("-Y")
+		.arg("find-principals")
+		.arg("-s")
+		.arg(sigfile)
+		.arg("-f")
+		.arg(allowed_signers);
+
+	log::trace!("principal lookup command: {cmd:?}");
+
+	match cmd.output() {
+		Ok(output)
+			if output.status.success()
+				&& !output.stdout.is_empty() =>
+		{
+			SigStatus::Bad
+		}
+		_ => SigStatus::Unknown,
+	}
+}
+
+impl Verify for SSHVerify {
+	fn verify(
+		&self,
+		commit: &str,
+		signature: &str,
+	) -> Result<VerificationResult, VerifyError> {
+		use std::io::Write;
+		use std::process::{Command, Stdio};
+
+		// `ssh-keygen -Y verify` checks the signature against the principal
+		// (identity) claiming to have made it, looked up in the allowed-signers file.
+		let identity = extract_committer_email(commit).ok_or_else(|| {
+			VerifyError::Shellout(String::from(
+				"could not determine committer identity from commit data",
+			))
+		})?;
+
+		let workdir = sign_tempdir("verify");
+		std::fs::create_dir_all(&workdir)
+			.map_err(|e| VerifyError::Output(e.to_string()))?;
+
+		let sigfile = workdir.join("commit.sig");
+		std::fs::write(&sigfile, signature)
+			.map_err(|e| VerifyError::WriteBuffer(e.to_string()))?;
+
+		let mut cmd = Command::new(&self.program);
+		cmd.stdin(Stdio::piped())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.arg("-Y")
+			.arg("verify")
+			.arg("-f")
+			.arg(&self.allowed_signers)
+			.arg("-I")
+			.arg(&identity)
+			.arg("-n")
+			.arg("git")
+			.arg("-s")
+			.arg(&sigfile);
+
+		log::trace!("verification command: {cmd:?}");
+
+		let mut child = cmd
+			.spawn()
+			.map_err(|e| VerifyError::Spawn(e.to_string()))?;
+
+		let mut stdin =
+			child.stdin.take().ok_or(VerifyError::Stdin)?;
+		write!(stdin, "{commit}")
+			.map_err(|e| VerifyError::WriteBuffer(e.to_string()))?;
+		drop(stdin);
+
+		let output = child
+			.wait_with_output()
+			.map_err(|e| VerifyError::Output(e.to_string()))?;
+
+		let stdout = std::str::from_utf8(&output.stdout)
+			.map_err(|e| VerifyError::Shellout(e.to_string()))?;
+		let stderr = std::str::from_utf8(&output.stderr)
+			.map_err(|e| VerifyError::Shellout(e.to_string()))?;
+
+		let result = if output.status.success()
+			&& stdout.contains("Good ")
+		{
+			VerificationResult {
+				status: SigStatus::Good,
+				signer: Some(identity),
+				fingerprint: None,
+				error: None,
+			}
+		} else {
+			VerificationResult {
+				status: classify_ssh_verify_failure(
+					&self.program,
+					&self.allowed_signers,
+					&sigfile,
+				),
+				signer: Some(identity),
+				fingerprint: None,
+				error: Some(format!("{stdout}{stderr}")),
+			}
+		};
+
+		let _ = std::fs::remove_dir_all(&workdir);
+
+		Ok(result)
+	}
+
+	#[cfg(test)]
+	fn program(&self) -> &String {
+		&self.program
+	}
+}
+
+/// Extract the committer's email address from a raw commit buffer, e.g.
+/// `committer Name <email> 1700000000 +0000`.
+fn extract_committer_email(commit: &str) -> Option<String> {
+	commit.lines().find_map(|line| {
+		let rest = line.strip_prefix("committer ")?;
+		let start = rest.find('<')?;
+		let end = rest.find('>')?;
+		Some(rest[start + 1..end].to_string())
+	})
+}
+
+/// A unique, process-local scratch directory under the system temp dir, used to stage
+/// the files `gpg`/`gpgsm`/`ssh-keygen` operate on.
+fn sign_tempdir(label: &str) -> std::path::PathBuf {
+	std::env::temp_dir().join(format!(
+		"gitui-{label}-{}-{}",
+		std::process::id(),
+		std::time::SystemTime::now()
+			.duration_since(std::time::UNIX_EPOCH)
+			.map(|d| d.as_nanos())
+			.unwrap_or_default()
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::error::Result;
+	use crate::sync::tests::repo_init_empty;
+
+	#[test]
+	fn test_invalid_signing_format() -> Result<()> {
+		let (_temp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "INVALID_SIGNING_FORMAT")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?);
+
+		assert!(sign.is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_program_and_signing_key_defaults() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("gpg", sign.program());
+		assert_eq!("name <email>", sign.signing_key());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_gpg_program_configs() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.program", "GPG_PROGRAM_TEST")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		// we get gpg.program, because gpg.openpgp.program is not set
+		assert_eq!("GPG_PROGRAM_TEST", sign.program());
+
+		{
+			let mut config = repo.config()?;
+			config.set_str(
+				"gpg.openpgp.program",
+				"GPG_OPENPGP_PROGRAM_TEST",
+			)?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		// since gpg.openpgp.program is now set as well, it is more specific than
+		// gpg.program and therefore takes precedence
+		assert_eq!("GPG_OPENPGP_PROGRAM_TEST", sign.program());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_user_signingkey() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("user.signingKey", "FFAA")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("FFAA", sign.signing_key());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_x509_program_and_signing_key() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "x509")?;
+			config.set_str("user.signingKey", "0xDEADBEEF")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		// defaults to "gpgsm", gpg.program is not a fallback for x509
+		assert_eq!("gpgsm", sign.program());
+		assert_eq!("0xDEADBEEF", sign.signing_key());
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.program", "GPG_PROGRAM_TEST")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		// gpg.program must not leak into the x509 program resolution
+		assert_eq!("gpgsm", sign.program());
+
+		{
+			let mut config = repo.config()?;
+			config.set_str(
+				"gpg.x509.program",
+				"GPGSM_PROGRAM_TEST",
+			)?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("GPGSM_PROGRAM_TEST", sign.program());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_x509_missing_signing_key() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "x509")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?);
+
+		assert!(sign.is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_ssh_program_and_signing_key_defaults() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "ssh")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("ssh-keygen", sign.program());
+		// no `user.signingKey` configured, the agent is consulted at sign time
+		assert_eq!("", sign.signing_key());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_ssh_program_config() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "ssh")?;
+			config.set_str(
+				"gpg.ssh.program",
+				"SSH_KEYGEN_PROGRAM_TEST",
+			)?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("SSH_KEYGEN_PROGRAM_TEST", sign.program());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_ssh_literal_signing_key() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "ssh")?;
+			config.set_str(
+				"user.signingKey",
+				"ssh-ed25519 AAAATESTKEY",
+			)?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("ssh-ed25519 AAAATESTKEY", sign.signing_key());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_ssh_cache_passphrase_config() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "ssh")?;
+			config.set_bool(
+				"gitui.signing.cachePassphrase",
+				true,
+			)?;
+		}
+
+		// resolving the signing method itself must not touch the keyring; that only
+		// happens lazily when a passphrase-protected key file is signed with.
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("ssh-keygen", sign.program());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_rust_signing_requires_key_file() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gitui.signing_methods", "rust")?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?);
+
+		assert!(sign.is_err());
+
+		{
+			let mut config = repo.config()?;
+			config.set_str(
+				"gitui.signing.keyFile",
+				"/tmp/does-not-matter-for-this-test.asc",
+			)?;
+		}
+
+		let sign =
+			SignBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!(
+			"/tmp/does-not-matter-for-this-test.asc",
+			sign.signing_key()
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_verify_builder_program_defaults() -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		let verify =
+			VerifyBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("gpg", verify.program());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_verify_builder_ssh_requires_allowed_signers_file(
+	) -> Result<()> {
+		let (_tmp_dir, repo) = repo_init_empty()?;
+
+		{
+			let mut config = repo.config()?;
+			config.set_str("gpg.format", "ssh")?;
+		}
+
+		let verify =
+			VerifyBuilder::from_gitconfig(&repo, &repo.config()?);
+
+		assert!(verify.is_err());
+
+		{
+			let mut config = repo.config()?;
+			config.set_str(
+				"gpg.ssh.allowedSignersFile",
+				"/tmp/allowed_signers",
+			)?;
+		}
+
+		let verify =
+			VerifyBuilder::from_gitconfig(&repo, &repo.config()?)?;
+
+		assert_eq!("ssh-keygen", verify.program());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_parse_gnupg_status_goodsig() {
+		let stderr = "\n[GNUPG:] NEWSIG\n[GNUPG:] GOODSIG FFAA Jane Doe <jane@example.com>\n[GNUPG:] VALIDSIG 0123456789ABCDEF0123456789ABCDEF01234567 2024-01-01 1700000000 0 4 0 1 10 00 0123456789ABCDEF0123456789ABCDEF01234567\n[GNUPG:] TRUST_FULLY 0 shell\n";
+
+		let result = parse_gnupg_status(stderr);
+
+		assert_eq!(result.status, SigStatus::Good);
+		assert_eq!(
+			result.signer.as_deref(),
+			Some("Jane Doe <jane@example.com>")
+		);
+		assert_eq!(
+			result.fingerprint.as_deref(),
+			Some(
+				"0123456789ABCDEF0123456789ABCDEF01234567"
+			)
+		);
+		assert!(result.error.is_none());
+	}
+
+	#[test]
+	fn test_parse_gnupg_status_badsig() {
+		let stderr = "\n[GNUPG:] BADSIG FFAA Jane Doe <jane@example.com>\n";
+
+		let result = parse_gnupg_status(stderr);
+
+		assert_eq!(result.status, SigStatus::Bad);
+		assert!(result.error.is_some());
+	}
+
+	#[test]
+	fn test_parse_gnupg_status_undefined_trust_is_unknown() {
+		let stderr = "\n[GNUPG:] GOODSIG FFAA Jane Doe <jane@example.com>\n[GNUPG:] TRUST_UNDEFINED\n";
+
+		let result = parse_gnupg_status(stderr);
+
+		assert_eq!(result.status, SigStatus::Unknown);
+	}
+
+	#[test]
+	fn test_parse_gnupg_status_marginal_trust_is_unknown() {
+		let stderr = "\n[GNUPG:] GOODSIG FFAA Jane Doe <jane@example.com>\n[GNUPG:] TRUST_MARGINAL 0 pgp\n";
+
+		let result = parse_gnupg_status(stderr);
+
+		assert_eq!(result.status, SigStatus::Unknown);
+	}
+
+	#[test]
+	fn test_extract_committer_email() {
+		let commit = "tree 0123456789abcdef0123456789abcdef01234567\nauthor Jane Doe <jane@example.com> 1700000000 +0000\ncommitter Jane Doe <jane@example.com> 1700000000 +0000\n\nmessage\n";
+
+		assert_eq!(
+			extract_committer_email(commit).as_deref(),
+			Some("jane@example.com")
+		);
 	}
 }